@@ -28,6 +28,11 @@ use tracing::{debug, info, span, Level};
 pub trait QuerySet: Send + Sync {
     /// Evaluates the queries and records which archetypes they require access to into a bitset.
     fn filter_archetypes(&mut self, world: &World, archetypes: &mut BitSet);
+
+    /// Evaluates the queries and returns the archetypes each one matches individually, in the
+    /// order the queries were added, rather than merging them into one shared bitset. Used to
+    /// detect queries within the same system whose archetypes overlap.
+    fn filter_archetypes_individually(&mut self, world: &World) -> Vec<BitSet>;
 }
 
 macro_rules! queryset_tuple {
@@ -52,6 +57,15 @@ macro_rules! impl_queryset_tuple {
 
                     $( $ty.filter_archetypes(world, bitset); )*
                 }
+
+                fn filter_archetypes_individually(&mut self, world: &World) -> Vec<BitSet> {
+                    let ($($ty,)*) = self;
+                    let mut archetypes = Vec::new();
+
+                    $( archetypes.extend($ty.filter_archetypes_individually(world)); )*
+
+                    archetypes
+                }
             }
     };
 }
@@ -64,6 +78,8 @@ queryset_tuple!(A, B, C, D, E, F, G, H);
 
 impl QuerySet for () {
     fn filter_archetypes(&mut self, _: &World, _: &mut BitSet) {}
+
+    fn filter_archetypes_individually(&mut self, _: &World) -> Vec<BitSet> { Vec::new() }
 }
 
 impl<AV, AF> QuerySet for Query<AV, AF>
@@ -76,6 +92,12 @@ where
             bitset.insert(arch as usize);
         }
     }
+
+    fn filter_archetypes_individually(&mut self, world: &World) -> Vec<BitSet> {
+        let mut bitset = BitSet::default();
+        self.filter_archetypes(world, &mut bitset);
+        vec![bitset]
+    }
 }
 
 /// Structure describing the resource and component access conditions of the system.
@@ -85,6 +107,18 @@ pub struct SystemAccess {
     components: Permissions<ComponentTypeId>,
 }
 
+impl SystemAccess {
+    /// Returns `true` if this access declares no writes, to either a component or a resource.
+    ///
+    /// A system for which this holds can never observe (or cause) aliased mutation through its
+    /// queries or resource fetches, so borrows derived from its `SubWorld` and read-resource
+    /// fetches are sound to tie to the world's lifetime rather than to the shorter lifetime of
+    /// the fetch itself.
+    pub fn is_read_only(&self) -> bool {
+        self.resources.writes().is_empty() && self.components.writes().is_empty()
+    }
+}
+
 /// A diagnostic identifier for a system.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SystemId {
@@ -92,6 +126,99 @@ pub struct SystemId {
     type_id: TypeId,
 }
 
+/// The result of evaluating a system's run criteria, determining whether (and how) it executes
+/// on a given schedule tick.
+///
+/// `YesAndCheckAgain`/`NoAndCheckAgain` re-evaluate the criteria in a tight loop on the calling
+/// thread with no yield between iterations, so a criteria closure relying on either must flip to
+/// `Yes`/`No` within a bounded number of re-checks as it is driven - e.g. by decrementing an
+/// internal counter each time it is called, rather than waiting on state mutated elsewhere (a
+/// wall-clock timer, another thread). See [`Runnable::run_unsafe`] for the hard cap that protects
+/// against a criteria which never converges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShouldRun {
+    /// Run the system this tick.
+    Yes,
+    /// Skip the system this tick.
+    No,
+    /// Run the system this tick, then immediately re-evaluate the criteria again before moving
+    /// on, allowing a system to catch up multiple iterations within a single tick.
+    YesAndCheckAgain,
+    /// Skip the system this tick, then immediately re-evaluate the criteria again.
+    NoAndCheckAgain,
+}
+
+/// The number of times [`Runnable::run_unsafe`] will evaluate a system's run criteria within a
+/// single tick, the first evaluation included. A criteria still returning
+/// [`YesAndCheckAgain`](ShouldRun::YesAndCheckAgain)/[`NoAndCheckAgain`](ShouldRun::NoAndCheckAgain)
+/// after that many evaluations is treated as [`ShouldRun::No`] for the rest of the tick, so a
+/// criteria that never converges cannot hang the calling thread.
+const MAX_RUN_CRITERIA_REEVALUATIONS: u32 = 1_000;
+
+/// A run criteria which yields [`ShouldRun::Yes`] the first time it is evaluated, and
+/// [`ShouldRun::No`] on every evaluation after that. This mirrors the common need for
+/// startup-only systems, without having to split them out into their own schedule.
+pub fn run_once() -> impl FnMut(&World, &Resources) -> ShouldRun {
+    let mut has_run = false;
+    move |_, _| {
+        if has_run {
+            ShouldRun::No
+        } else {
+            has_run = true;
+            ShouldRun::Yes
+        }
+    }
+}
+
+/// A non-owning pointer to a [`World`] which carries the real world lifetime `'w` in its
+/// [`PhantomData`] instead of forging it as `'static`.
+///
+/// This gives callers that need to smuggle a world reference past the borrow checker (such as
+/// [`FunctionSystem::get_readonly`], which ties a [`SubWorld`] to the world's own lifetime) one
+/// narrow, documented place to do so, rather than an ad-hoc `transmute` at each call site.
+#[derive(Clone, Copy)]
+pub struct UnsafeWorldCell<'w> {
+    world: *const World,
+    _marker: PhantomData<&'w World>,
+}
+
+impl<'w> UnsafeWorldCell<'w> {
+    /// Creates a cell over `world`, inheriting its lifetime.
+    pub fn new(world: &'w World) -> Self {
+        Self { world: world as *const World, _marker: PhantomData }
+    }
+
+    /// Returns a shared reference to the world with the cell's original lifetime.
+    ///
+    /// # Safety
+    /// The caller must not hold this reference concurrently with a `&mut World` derived from the
+    /// same cell, and must not let it outlive the borrow that created the cell.
+    pub unsafe fn world(self) -> &'w World { &*self.world }
+
+    /// Returns a [`SubWorld`] borrowing from the cell's original lifetime `'w`. The returned
+    /// `SubWorld` refers to `access` and `archetypes`, so both must be borrowed for `'w` as well.
+    ///
+    /// This is only sound for a system proven [read-only](SystemAccess::is_read_only): such a
+    /// system can never hold a conflicting `&mut World`/write-access `SubWorld` derived from the
+    /// same cell while this one is alive, so tying the result to `'w` cannot introduce aliasing.
+    ///
+    /// # Safety
+    /// Same contract as [`world`](Self::world), and the caller must have already confirmed
+    /// `access.is_read_only()`.
+    pub unsafe fn get_readonly(
+        self,
+        access: &'w SystemAccess,
+        archetypes: &'w ArchetypeAccess,
+    ) -> SubWorld<'w> {
+        let component_access = ComponentAccess::Allow(Cow::Borrowed(&access.components));
+        SubWorld::new_unchecked(self.world(), component_access, archetypes.bitset())
+    }
+}
+
+impl<'w> From<&'w World> for UnsafeWorldCell<'w> {
+    fn from(world: &'w World) -> Self { Self::new(world) }
+}
+
 struct Unspecified;
 
 impl std::fmt::Display for SystemId {
@@ -119,27 +246,35 @@ impl<T: Into<Cow<'static, str>>> From<T> for SystemId {
 ///
 /// Queries are stored generically within this struct, and the `SystemQuery` types are generated
 /// on each `run` call, wrapping the world and providing the set to the user in their closure.
-pub struct System<R, Q, F, I, D> {
+pub struct System<R, Q, F, I, D, C = fn(&World, &Resources) -> ShouldRun> {
     name: SystemId,
     _resources: PhantomData<R>,
     queries: Q,
     run_fn: F,
     init_fn: Option<I>,
     dispose_fn: Option<D>,
+    run_criteria: Option<C>,
     archetypes: ArchetypeAccess,
     access: SystemAccess,
 
+    // The component access declared by each query added via `with_query`, in the order they were
+    // added. Used to detect queries within this system whose archetypes overlap and whose access
+    // conflicts, which `access` alone cannot tell apart since it only stores the union.
+    query_accesses: Vec<Permissions<ComponentTypeId>>,
+    query_access_checked: bool,
+
     // We pre-allocate a command buffer for ourself. Writes are self-draining so we never have to rellocate.
     command_buffer: HashMap<WorldId, CommandBuffer>,
 }
 
-impl<R, Q, F, I, D> Runnable for System<R, Q, F, I, D>
+impl<R, Q, F, I, D, C> Runnable for System<R, Q, F, I, D, C>
 where
     R: for<'a> ResourceSet<'a>,
     Q: QuerySet,
     F: SystemFn<R, Q>,
     I: FnOnce(&mut World, &mut Resources),
     D: FnOnce(&mut World, &mut Resources),
+    C: FnMut(&World, &Resources) -> ShouldRun,
 {
     fn name(&self) -> &SystemId { &self.name }
 
@@ -158,6 +293,12 @@ where
     }
 
     fn prepare(&mut self, world: &World) {
+        if !self.query_access_checked {
+            let per_query_archetypes = self.queries.filter_archetypes_individually(world);
+            assert_query_access_compatible(&self.name, &self.query_accesses, &per_query_archetypes);
+            self.query_access_checked = true;
+        }
+
         if let ArchetypeAccess::Some(bitset) = &mut self.archetypes {
             self.queries.filter_archetypes(world, bitset);
         }
@@ -182,20 +323,55 @@ where
     }
 
     unsafe fn run_unsafe(&mut self, world: &World, resources: &Resources) {
+        for _ in 0..MAX_RUN_CRITERIA_REEVALUATIONS {
+            let should_run = match &mut self.run_criteria {
+                Some(criteria) => criteria(world, resources),
+                None => ShouldRun::Yes,
+            };
+
+            match should_run {
+                ShouldRun::No => return,
+                ShouldRun::NoAndCheckAgain => continue,
+                ShouldRun::Yes => {
+                    self.run_once(world, resources);
+                    return;
+                }
+                ShouldRun::YesAndCheckAgain => self.run_once(world, resources),
+            }
+        }
+
+        // A criteria that never converges to `Yes`/`No` within the cap is treated as `No` for
+        // this tick rather than spinning the calling thread forever - see
+        // `MAX_RUN_CRITERIA_REEVALUATIONS`.
+        debug!(
+            "run criteria for system `{}` did not converge within {} evaluations, skipping \
+             this tick",
+            self.name, MAX_RUN_CRITERIA_REEVALUATIONS
+        );
+    }
+}
+
+impl<R, Q, F, I, D, C> System<R, Q, F, I, D, C>
+where
+    R: for<'a> ResourceSet<'a>,
+    Q: QuerySet,
+    F: SystemFn<R, Q>,
+{
+    /// Returns `true` if this system's declared access contains no writes, see
+    /// [`SystemAccess::is_read_only`].
+    pub fn is_read_only(&self) -> bool { self.access.is_read_only() }
+
+    unsafe fn run_once<'a>(&mut self, world: &World, resources: &'a Resources) {
         let span = span!(Level::INFO, "System", system = %self.name);
         let _guard = span.enter();
 
         debug!("Initializing");
 
-        // safety:
-        // It is difficult to correctly communicate the lifetime of the resource fetch through to the system closure.
-        // We are hacking this by passing the fetch with a static lifetime to its internal references.
-        // This is sound because the fetch structs only provide access to the resource through reborrows on &self.
-        // As the fetch struct is created on the stack here, and the resources it is holding onto is a parameter to this function,
-        // we know for certain that the lifetime of the fetch struct (which constrains the lifetime of the resource the system sees)
-        // must be shorter than the lifetime of the resource.
-        let resources_static = std::mem::transmute::<_, &'static Resources>(resources);
-        let mut resources = R::fetch_unchecked(resources_static);
+        // `resources` keeps its real lifetime `'a` all the way through the fetch: `R` is bound
+        // `for<'a> ResourceSet<'a>`, so `R::fetch_unchecked` can be called directly on the
+        // borrow `run_unsafe` was actually given, and `run_fn.run` is generic over the same
+        // `'a` - no `'static` cast or erasure cell is needed anywhere in this path.
+        let mut resources = R::fetch_unchecked(resources);
 
         let queries = &mut self.queries;
         let component_access = ComponentAccess::Allow(Cow::Borrowed(&self.access.components));
@@ -212,31 +388,83 @@ where
     }
 }
 
+/// Panics if two queries within the same system both declare access to an archetype they both
+/// match, where at least one of them writes to a component the other also accesses.
+///
+/// Exclusivity only actually matters when the queries can match the same archetype, so the
+/// archetype bitsets are intersected first, and the component access is only compared - and a
+/// panic only raised - for pairs that overlap.
+fn assert_query_access_compatible(
+    system: &SystemId,
+    accesses: &[Permissions<ComponentTypeId>],
+    archetypes: &[BitSet],
+) {
+    for i in 0..accesses.len() {
+        for j in (i + 1)..accesses.len() {
+            if archetypes[i].intersection(&archetypes[j]).next().is_none() {
+                continue;
+            }
+
+            for id in accesses[i].writes() {
+                if accesses[j].reads().contains(id) || accesses[j].writes().contains(id) {
+                    panic!(
+                        "system `{}` has conflicting access between query {} and query {}: both \
+                         may access component {:?}, which query {} writes",
+                        system, i, j, id, i
+                    );
+                }
+            }
+            for id in accesses[j].writes() {
+                if accesses[i].reads().contains(id) || accesses[i].writes().contains(id) {
+                    panic!(
+                        "system `{}` has conflicting access between query {} and query {}: both \
+                         may access component {:?}, which query {} writes",
+                        system, i, j, id, j
+                    );
+                }
+            }
+        }
+    }
+}
+
 /// A function which can provide the body of a system.
-pub trait SystemFn<R: ResourceSet<'static>, Q: QuerySet> {
+///
+/// `run` is generic over the resource borrow's lifetime `'a` rather than fixing `R` at
+/// `ResourceSet<'static>`, so that [`System::run_once`] can fetch resources directly off the
+/// `&Resources` it was actually given instead of forging a `'static` borrow to satisfy this
+/// trait.
+pub trait SystemFn<R, Q>
+where
+    Q: QuerySet,
+{
     /// Runs the system body.
-    fn run(
+    fn run<'a>(
         &mut self,
         commands: &mut CommandBuffer,
         world: &mut SubWorld,
-        resources: &mut R::Result,
+        resources: &mut <R as ResourceSet<'a>>::Result,
         queries: &mut Q,
-    );
+    )
+    where
+        R: ResourceSet<'a>;
 }
 
 impl<F, R, Q> SystemFn<R, Q> for F
 where
-    R: ResourceSet<'static>,
     Q: QuerySet,
-    F: FnMut(&mut CommandBuffer, &mut SubWorld, &mut R::Result, &mut Q) + 'static,
+    F: for<'a> FnMut(&mut CommandBuffer, &mut SubWorld, &mut <R as ResourceSet<'a>>::Result, &mut Q)
+        + 'static,
 {
-    fn run(
+    fn run<'a>(
         &mut self,
         commands: &mut CommandBuffer,
         world: &mut SubWorld,
-        resources: &mut R::Result,
+        resources: &mut <R as ResourceSet<'a>>::Result,
         queries: &mut Q,
-    ) {
+    )
+    where
+        R: ResourceSet<'a>,
+    {
         (self)(commands, world, resources, queries)
     }
 }
@@ -276,18 +504,29 @@ pub struct SystemBuilder<
     R = (),
     I = fn(&mut World, &mut Resources),
     D = fn(&mut World, &mut Resources),
+    C = fn(&World, &Resources) -> ShouldRun,
 > {
     name: SystemId,
     queries: Q,
     resources: R,
     resource_access: Permissions<ResourceTypeId>,
     component_access: Permissions<ComponentTypeId>,
+    query_accesses: Vec<Permissions<ComponentTypeId>>,
     access_all_archetypes: bool,
     init_fn: Option<I>,
     dispose_fn: Option<D>,
+    run_criteria: Option<C>,
 }
 
-impl SystemBuilder<(), (), fn(&mut World, &mut Resources), fn(&mut World, &mut Resources)> {
+impl
+    SystemBuilder<
+        (),
+        (),
+        fn(&mut World, &mut Resources),
+        fn(&mut World, &mut Resources),
+        fn(&World, &Resources) -> ShouldRun,
+    >
+{
     /// Create a new system builder to construct a new system.
     ///
     /// Please note, the `name` argument for this method is just for debugging and visualization
@@ -299,19 +538,22 @@ impl SystemBuilder<(), (), fn(&mut World, &mut Resources), fn(&mut World, &mut R
             resources: (),
             resource_access: Permissions::default(),
             component_access: Permissions::default(),
+            query_accesses: Vec::new(),
             access_all_archetypes: false,
             init_fn: None,
             dispose_fn: None,
+            run_criteria: None,
         }
     }
 }
 
-impl<Q, R, I, D> SystemBuilder<Q, R, I, D>
+impl<Q, R, I, D, C> SystemBuilder<Q, R, I, D, C>
 where
     Q: 'static + Send + ConsFlatten,
     R: 'static + Send + ConsFlatten,
     I: FnOnce(&mut World, &mut Resources),
     D: FnOnce(&mut World, &mut Resources),
+    C: FnMut(&World, &Resources) -> ShouldRun,
 {
     /// Defines a query to provide this system for its execution. Multiple queries can be provided,
     /// and queries are cached internally for efficiency for filtering and archetype ID handling.
@@ -321,13 +563,14 @@ where
     pub fn with_query<V, F>(
         mut self,
         query: Query<V, F>,
-    ) -> SystemBuilder<<Q as ConsAppend<Query<V, F>>>::Output, R, I, D>
+    ) -> SystemBuilder<<Q as ConsAppend<Query<V, F>>>::Output, R, I, D, C>
     where
         V: for<'a> View<'a>,
         F: 'static + EntityFilter,
         Q: ConsAppend<Query<V, F>>,
     {
         self.component_access.add(V::requires_permissions());
+        self.query_accesses.push(V::requires_permissions());
 
         SystemBuilder {
             name: self.name,
@@ -335,9 +578,11 @@ where
             resources: self.resources,
             resource_access: self.resource_access,
             component_access: self.component_access,
+            query_accesses: self.query_accesses,
             access_all_archetypes: self.access_all_archetypes,
             init_fn: self.init_fn,
             dispose_fn: self.dispose_fn,
+            run_criteria: self.run_criteria,
         }
     }
 
@@ -345,7 +590,9 @@ where
     ///
     /// This will inform the dispatcher to not allow any writes access to this resource while
     /// this system is running. Parralel reads still occur during execution.
-    pub fn read_resource<T>(mut self) -> SystemBuilder<Q, <R as ConsAppend<Read<T>>>::Output, I, D>
+    pub fn read_resource<T>(
+        mut self,
+    ) -> SystemBuilder<Q, <R as ConsAppend<Read<T>>>::Output, I, D, C>
     where
         T: 'static + Resource,
         R: ConsAppend<Read<T>>,
@@ -359,9 +606,11 @@ where
             resources: ConsAppend::append(self.resources, Read::<T>::default()),
             resource_access: self.resource_access,
             component_access: self.component_access,
+            query_accesses: self.query_accesses,
             access_all_archetypes: self.access_all_archetypes,
             init_fn: self.init_fn,
             dispose_fn: self.dispose_fn,
+            run_criteria: self.run_criteria,
         }
     }
 
@@ -371,7 +620,7 @@ where
     /// this system is running.
     pub fn write_resource<T>(
         mut self,
-    ) -> SystemBuilder<Q, <R as ConsAppend<Write<T>>>::Output, I, D>
+    ) -> SystemBuilder<Q, <R as ConsAppend<Write<T>>>::Output, I, D, C>
     where
         T: 'static + Resource,
         R: ConsAppend<Write<T>>,
@@ -385,9 +634,11 @@ where
             resources: ConsAppend::append(self.resources, Write::<T>::default()),
             resource_access: self.resource_access,
             component_access: self.component_access,
+            query_accesses: self.query_accesses,
             access_all_archetypes: self.access_all_archetypes,
             init_fn: self.init_fn,
             dispose_fn: self.dispose_fn,
+            run_criteria: self.run_criteria,
         }
     }
 
@@ -436,7 +687,7 @@ where
     /// to the first system execution.
     ///
     /// Init functions are called by `Schedule::init()` in the order systems were added to the schedule.
-    pub fn with_init<F>(self, init_fn: F) -> SystemBuilder<Q, R, F, D>
+    pub fn with_init<F>(self, init_fn: F) -> SystemBuilder<Q, R, F, D, C>
     where
         F: FnOnce(&mut World, &mut Resources),
     {
@@ -446,9 +697,11 @@ where
             resources: self.resources,
             resource_access: self.resource_access,
             component_access: self.component_access,
+            query_accesses: self.query_accesses,
             access_all_archetypes: self.access_all_archetypes,
             init_fn: Some(init_fn),
             dispose_fn: self.dispose_fn,
+            run_criteria: self.run_criteria,
         }
     }
 
@@ -457,7 +710,7 @@ where
     /// is disposed.
     ///
     /// Dispose functions are called by `Schedule::dispose()` in the order systems were added to the schedule.
-    pub fn with_dispose<F>(self, dispose_fn: F) -> SystemBuilder<Q, R, I, F>
+    pub fn with_dispose<F>(self, dispose_fn: F) -> SystemBuilder<Q, R, I, F, C>
     where
         F: FnOnce(&mut World, &mut Resources),
     {
@@ -467,9 +720,36 @@ where
             resources: self.resources,
             resource_access: self.resource_access,
             component_access: self.component_access,
+            query_accesses: self.query_accesses,
             access_all_archetypes: self.access_all_archetypes,
             init_fn: self.init_fn,
             dispose_fn: Some(dispose_fn),
+            run_criteria: self.run_criteria,
+        }
+    }
+
+    /// Adds a run criteria to the system, gating whether it executes on a given schedule tick.
+    ///
+    /// The criteria is evaluated each time the system would otherwise run, and may itself request
+    /// to be re-evaluated immediately via [`ShouldRun::YesAndCheckAgain`]/
+    /// [`ShouldRun::NoAndCheckAgain`], which lets a system run multiple times (or be skipped
+    /// repeatedly) within a single tick - useful for fixed-timestep-style catch up. See
+    /// [`run_once`] for a ready-made criteria covering the common startup-system case.
+    pub fn with_run_criteria<F>(self, run_criteria: F) -> SystemBuilder<Q, R, I, D, F>
+    where
+        F: FnMut(&World, &Resources) -> ShouldRun,
+    {
+        SystemBuilder {
+            name: self.name,
+            queries: self.queries,
+            resources: self.resources,
+            resource_access: self.resource_access,
+            component_access: self.component_access,
+            query_accesses: self.query_accesses,
+            access_all_archetypes: self.access_all_archetypes,
+            init_fn: self.init_fn,
+            dispose_fn: self.dispose_fn,
+            run_criteria: Some(run_criteria),
         }
     }
 
@@ -480,7 +760,7 @@ where
     pub fn build<F>(
         self,
         run_fn: F,
-    ) -> System<<R as ConsFlatten>::Output, <Q as ConsFlatten>::Output, F, I, D>
+    ) -> System<<R as ConsFlatten>::Output, <Q as ConsFlatten>::Output, F, I, D, C>
     where
         <R as ConsFlatten>::Output: for<'a> ResourceSet<'a> + Send + Sync,
         <Q as ConsFlatten>::Output: QuerySet,
@@ -496,6 +776,7 @@ where
             run_fn,
             init_fn: self.init_fn,
             dispose_fn: self.dispose_fn,
+            run_criteria: self.run_criteria,
             _resources: PhantomData::<<R as ConsFlatten>::Output>,
             queries: self.queries.flatten(),
             archetypes: if self.access_all_archetypes {
@@ -507,7 +788,676 @@ where
                 resources: self.resource_access,
                 components: self.component_access,
             },
+            query_accesses: self.query_accesses,
+            query_access_checked: false,
             command_buffer: HashMap::default(),
         }
     }
 }
+
+// ---------------------------------------------------------------------------------------------
+// Function-signature systems
+//
+// `IntoSystem` offers an alternative to `SystemBuilder` for the common case: rather than hand
+// assembling access via `.with_query`/`.read_resource`, a plain function can be converted
+// directly into a `FunctionSystem` by inspecting its parameter list. Each parameter declares its own
+// access through `SystemParam`/`SystemParamState`, so `IntoSystem` can compute the whole
+// `SystemAccess` up front by walking every parameter - unlike the builder's cons-list, which only
+// grows as methods are chained, parameters here may be declared in any order.
+// ---------------------------------------------------------------------------------------------
+
+/// Persistent, per-parameter state retained alongside a function-signature system between runs.
+///
+/// Most parameters (resources) carry no state of their own; `Query` uses this to retain its
+/// matched-archetype cache, and `CommandBuffer` uses it to retain its per-`World` buffers.
+pub trait SystemParamState: Default + Send + Sync + 'static {
+    /// Declares the resource and component access required by this parameter, and flags
+    /// `access_all_archetypes` if it needs visibility into archetypes beyond those it queries.
+    fn declare_access(
+        resources: &mut Permissions<ResourceTypeId>,
+        components: &mut Permissions<ComponentTypeId>,
+        access_all_archetypes: &mut bool,
+    );
+
+    /// Registers the archetypes this parameter will touch into the system's archetype bitset.
+    /// Most parameters have nothing to register; `Query` overrides this with its own matches.
+    fn filter_archetypes(&mut self, _world: &World, _bitset: &mut BitSet) {}
+
+    /// Returns this parameter's command buffer for `world`, if it has one.
+    /// Most parameters don't; `CommandBufferState` overrides this with its own buffers.
+    fn command_buffer_mut(&mut self, _world: WorldId) -> Option<&mut CommandBuffer> { None }
+}
+
+impl SystemParamState for () {
+    fn declare_access(
+        _: &mut Permissions<ResourceTypeId>,
+        _: &mut Permissions<ComponentTypeId>,
+        _: &mut bool,
+    ) {
+    }
+}
+
+/// A parameter that can be extracted from a [`World`] and [`Resources`] for use in a plain
+/// function converted into a [`FunctionSystem`] by [`IntoSystem`].
+pub trait SystemParam<'a>: Sized {
+    /// State persisted between system executions for this parameter.
+    type State: SystemParamState;
+
+    /// The value produced by [`fetch`](SystemParam::fetch) for a single system execution.
+    type Item;
+
+    /// Fetches this parameter's value for a single system execution.
+    fn fetch(
+        state: &'a mut Self::State,
+        resources: &'a Resources,
+        world: &'a World,
+        access: &'a SystemAccess,
+        archetypes: &'a ArchetypeAccess,
+    ) -> Self::Item;
+}
+
+impl<T: 'static + Resource> SystemParamState for Read<T> {
+    fn declare_access(
+        resources: &mut Permissions<ResourceTypeId>,
+        _: &mut Permissions<ComponentTypeId>,
+        _: &mut bool,
+    ) {
+        resources.push_read(ResourceTypeId::of::<T>());
+    }
+}
+
+/// The value fetched for a `Read<T>` [`SystemParam`].
+///
+/// Derefs to the underlying resource fetch, so existing call sites keep working unchanged;
+/// [`into_inner`](Self::into_inner) additionally moves that fetch out of the wrapper, for callers
+/// that want to hold onto it directly rather than through this binding.
+pub struct ReadResource<'a, T: Resource>(<Read<T> as ResourceSet<'a>>::Result);
+
+impl<'a, T: Resource> std::ops::Deref for ReadResource<'a, T> {
+    type Target = <Read<T> as ResourceSet<'a>>::Result;
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+impl<'a, T: Resource> ReadResource<'a, T> {
+    /// Moves the underlying resource fetch out of this wrapper.
+    pub fn into_inner(self) -> <Read<T> as ResourceSet<'a>>::Result { self.0 }
+}
+
+impl<'a, T: 'static + Resource> SystemParam<'a> for Read<T> {
+    type State = Read<T>;
+    type Item = ReadResource<'a, T>;
+
+    fn fetch(
+        _: &'a mut Self::State,
+        resources: &'a Resources,
+        _: &'a World,
+        _: &'a SystemAccess,
+        _: &'a ArchetypeAccess,
+    ) -> Self::Item {
+        ReadResource(Read::<T>::fetch_unchecked(resources))
+    }
+}
+
+impl<T: 'static + Resource> SystemParamState for Write<T> {
+    fn declare_access(
+        resources: &mut Permissions<ResourceTypeId>,
+        _: &mut Permissions<ComponentTypeId>,
+        _: &mut bool,
+    ) {
+        resources.push(ResourceTypeId::of::<T>());
+    }
+}
+
+impl<'a, T: 'static + Resource> SystemParam<'a> for Write<T> {
+    type State = Write<T>;
+    type Item = <Write<T> as ResourceSet<'a>>::Result;
+
+    fn fetch(
+        _: &'a mut Self::State,
+        resources: &'a Resources,
+        _: &'a World,
+        _: &'a SystemAccess,
+        _: &'a ArchetypeAccess,
+    ) -> Self::Item {
+        Write::<T>::fetch_unchecked(resources)
+    }
+}
+
+impl<V, F> SystemParamState for Query<V, F>
+where
+    V: for<'v> View<'v> + Send + Sync + 'static,
+    F: 'static + EntityFilter + Send + Sync,
+{
+    fn declare_access(
+        _: &mut Permissions<ResourceTypeId>,
+        components: &mut Permissions<ComponentTypeId>,
+        _: &mut bool,
+    ) {
+        components.add(V::requires_permissions());
+    }
+
+    fn filter_archetypes(&mut self, world: &World, bitset: &mut BitSet) {
+        for &ArchetypeIndex(arch) in self.find_archetypes(world) {
+            bitset.insert(arch as usize);
+        }
+    }
+}
+
+impl<'a, V, F> SystemParam<'a> for &'a mut Query<V, F>
+where
+    V: for<'v> View<'v> + Send + Sync + 'static,
+    F: 'static + EntityFilter + Send + Sync,
+{
+    type State = Query<V, F>;
+    type Item = &'a mut Query<V, F>;
+
+    fn fetch(
+        state: &'a mut Self::State,
+        _: &'a Resources,
+        _: &'a World,
+        _: &'a SystemAccess,
+        _: &'a ArchetypeAccess,
+    ) -> Self::Item {
+        state
+    }
+}
+
+/// Per-[`World`] command buffers retained by a `&mut CommandBuffer` system parameter, mirroring
+/// the `command_buffer` cache that `SystemBuilder`-constructed systems keep on [`System`] itself.
+#[derive(Default)]
+pub struct CommandBufferState {
+    buffers: HashMap<WorldId, CommandBuffer>,
+}
+
+impl SystemParamState for CommandBufferState {
+    fn declare_access(
+        _: &mut Permissions<ResourceTypeId>,
+        _: &mut Permissions<ComponentTypeId>,
+        _: &mut bool,
+    ) {
+    }
+
+    fn command_buffer_mut(&mut self, world: WorldId) -> Option<&mut CommandBuffer> {
+        self.buffers.get_mut(&world)
+    }
+}
+
+impl<'a> SystemParam<'a> for &'a mut CommandBuffer {
+    type State = CommandBufferState;
+    type Item = &'a mut CommandBuffer;
+
+    fn fetch(
+        state: &'a mut Self::State,
+        _: &'a Resources,
+        world: &'a World,
+        _: &'a SystemAccess,
+        _: &'a ArchetypeAccess,
+    ) -> Self::Item {
+        state
+            .buffers
+            .entry(world.id())
+            .or_insert_with(|| CommandBuffer::new(world))
+    }
+}
+
+/// Panics if `incoming` declares access that conflicts with `existing`, naming the offending
+/// parameter's position in the function's argument list.
+fn assert_no_access_conflict<T: Copy + Eq + std::fmt::Debug>(
+    existing: &Permissions<T>,
+    incoming: &Permissions<T>,
+    param_index: usize,
+) {
+    for id in incoming.writes() {
+        if existing.reads().contains(id) || existing.writes().contains(id) {
+            panic!(
+                "system parameter {} conflicts with an earlier parameter: both declare access to \
+                 {:?}, and at least one of them is exclusive",
+                param_index, id
+            );
+        }
+    }
+    for id in incoming.reads() {
+        if existing.writes().contains(id) {
+            panic!(
+                "system parameter {} conflicts with an earlier parameter: both declare access to \
+                 {:?}, and at least one of them is exclusive",
+                param_index, id
+            );
+        }
+    }
+}
+
+/// Converts a plain function into a [`FunctionSystem`] by inspecting its parameter list, each of which
+/// must implement [`SystemParam`]. Unlike [`SystemBuilder`], parameters may be declared in any
+/// order; `into_system` walks them up front to assemble the resulting system's access.
+/// ```rust,no_run
+/// # use legion::*;
+/// # #[derive(Copy, Clone, Debug, PartialEq)]
+/// # struct Position;
+/// # #[derive(Copy, Clone, Debug, PartialEq)]
+/// # struct Velocity;
+/// fn movement(query: &mut Query<(Write<Position>, Read<Velocity>)>, cmd: &mut CommandBuffer) {
+///     let _ = (query, cmd);
+/// }
+/// let _system = movement.into_system("movement".into());
+/// ```
+pub trait IntoSystem<Params> {
+    /// The concrete system produced from this function.
+    type System: Runnable;
+
+    /// Converts this function into a system.
+    fn into_system(self, name: SystemId) -> Self::System;
+}
+
+/// A system constructed from a plain function via [`IntoSystem`]. It implements [`Runnable`]
+/// itself and is a separate type from [`System`].
+pub struct FunctionSystem<Func, State> {
+    name: SystemId,
+    func: Func,
+    state: State,
+    access: SystemAccess,
+    archetypes: ArchetypeAccess,
+}
+
+impl<Func, State> FunctionSystem<Func, State> {
+    /// Returns `true` if this system's declared access contains no writes, see
+    /// [`SystemAccess::is_read_only`].
+    pub fn is_read_only(&self) -> bool { self.access.is_read_only() }
+
+    /// Borrows a [`SubWorld`] over `world` tied to `world`'s own lifetime rather than to a
+    /// transient fetch, letting a read-only system stash query/resource results - collected into
+    /// a `Vec`, say - past the point a regular parameter fetch would otherwise force a reborrow.
+    ///
+    /// Returns `None` if this system declares any write access; see
+    /// [`is_read_only`](Self::is_read_only).
+    ///
+    /// Only the `SubWorld` is tied to the world's lifetime. `Query` has no `iter_readonly`, and
+    /// [`ReadResource::into_inner`] returns a fetch bound to the parameter fetch's lifetime, not
+    /// to the world's.
+    pub fn get_readonly<'w>(&'w self, world: &'w World) -> Option<SubWorld<'w>> {
+        if !self.is_read_only() {
+            return None;
+        }
+
+        // safety: `is_read_only` above guarantees this system declares no write access, so a
+        // `SubWorld` tied to `world`'s own lifetime can never alias a `&mut World`/write-access
+        // `SubWorld` this system also holds.
+        Some(unsafe { UnsafeWorldCell::new(world).get_readonly(&self.access, &self.archetypes) })
+    }
+}
+
+macro_rules! into_system_tuple {
+    ($head_ty:ident) => {
+        impl_into_system_tuple!($head_ty);
+    };
+    ($head_ty:ident, $( $tail_ty:ident ),*) => (
+        impl_into_system_tuple!($head_ty, $( $tail_ty ),*);
+        into_system_tuple!($( $tail_ty ),*);
+    );
+}
+
+macro_rules! impl_into_system_tuple {
+    ($($ty: ident),*) => {
+        #[allow(unused_parens, non_snake_case, unused_mut, unused_assignments, unused_variables)]
+        impl<Func, $($ty),*> IntoSystem<($($ty,)*)> for Func
+        where
+            $( $ty: for<'a> SystemParam<'a>, )*
+            Func: for<'a> FnMut($( <$ty as SystemParam<'a>>::Item ),*) + 'static,
+        {
+            type System = FunctionSystem<Func, ($( <$ty as SystemParam<'_>>::State, )*)>;
+
+            fn into_system(self, name: SystemId) -> Self::System {
+                let mut resource_access = Permissions::default();
+                let mut component_access = Permissions::default();
+                let mut access_all_archetypes = false;
+                let mut index = 0usize;
+
+                $(
+                    let mut param_resources = Permissions::default();
+                    let mut param_components = Permissions::default();
+                    let mut param_all_archetypes = false;
+                    $ty::declare_access(&mut param_resources, &mut param_components, &mut param_all_archetypes);
+                    assert_no_access_conflict(&resource_access, &param_resources, index);
+                    assert_no_access_conflict(&component_access, &param_components, index);
+                    resource_access.add(param_resources);
+                    component_access.add(param_components);
+                    access_all_archetypes |= param_all_archetypes;
+                    index += 1;
+                )*
+
+                FunctionSystem {
+                    name,
+                    func: self,
+                    state: ($( <$ty as SystemParam<'_>>::State::default(), )*),
+                    archetypes: if access_all_archetypes {
+                        ArchetypeAccess::All
+                    } else {
+                        ArchetypeAccess::Some(BitSet::default())
+                    },
+                    access: SystemAccess {
+                        resources: resource_access,
+                        components: component_access,
+                    },
+                }
+            }
+        }
+
+        #[allow(unused_parens, non_snake_case, unused_variables)]
+        impl<Func, $($ty),*> Runnable for FunctionSystem<Func, ($( <$ty as SystemParam<'_>>::State, )*)>
+        where
+            $( $ty: for<'a> SystemParam<'a>, )*
+            Func: for<'a> FnMut($( <$ty as SystemParam<'a>>::Item ),*) + 'static,
+        {
+            fn name(&self) -> &SystemId { &self.name }
+
+            fn reads(&self) -> (&[ResourceTypeId], &[ComponentTypeId]) {
+                (&self.access.resources.reads(), &self.access.components.reads())
+            }
+
+            fn writes(&self) -> (&[ResourceTypeId], &[ComponentTypeId]) {
+                (&self.access.resources.writes(), &self.access.components.writes())
+            }
+
+            fn prepare(&mut self, world: &World) {
+                if let ArchetypeAccess::Some(bitset) = &mut self.archetypes {
+                    let ($($ty,)*) = &mut self.state;
+                    $( $ty.filter_archetypes(world, bitset); )*
+                }
+            }
+
+            fn init(&mut self, _: &mut World, _: &mut Resources) {}
+
+            fn dispose(&mut self, _: &mut World, _: &mut Resources) {}
+
+            fn accesses_archetypes(&self) -> &ArchetypeAccess { &self.archetypes }
+
+            fn command_buffer_mut(&mut self, world: WorldId) -> Option<&mut CommandBuffer> {
+                let ($($ty,)*) = &mut self.state;
+                let mut buffer: Option<&mut CommandBuffer> = None;
+                $(
+                    if buffer.is_none() {
+                        buffer = $ty.command_buffer_mut(world);
+                    }
+                )*
+                buffer
+            }
+
+            unsafe fn run_unsafe(&mut self, world: &World, resources: &Resources) {
+                let span = span!(Level::INFO, "System", system = %self.name);
+                let _guard = span.enter();
+
+                debug!("Initializing");
+                let ($($ty,)*) = &mut self.state;
+
+                info!("Running");
+                (self.func)($( $ty::fetch($ty, resources, world, &self.access, &self.archetypes) ),*);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "extended-tuple-impls")]
+into_system_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z);
+
+#[cfg(not(feature = "extended-tuple-impls"))]
+into_system_tuple!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Position;
+    struct Velocity;
+
+    fn permissions(
+        reads: &[ComponentTypeId],
+        writes: &[ComponentTypeId],
+    ) -> Permissions<ComponentTypeId> {
+        let mut permissions = Permissions::default();
+        for &id in reads {
+            permissions.push_read(id);
+        }
+        for &id in writes {
+            permissions.push(id);
+        }
+        permissions
+    }
+
+    fn archetypes(indices: &[usize]) -> BitSet {
+        let mut bitset = BitSet::default();
+        for &index in indices {
+            bitset.insert(index);
+        }
+        bitset
+    }
+
+    #[test]
+    fn disjoint_archetypes_never_conflict() {
+        let position = ComponentTypeId::of::<Position>();
+        let accesses = vec![
+            permissions(&[], &[position]),
+            permissions(&[], &[position]),
+        ];
+        let archetypes = vec![archetypes(&[0]), archetypes(&[1])];
+
+        assert_query_access_compatible(&SystemId::from("test"), &accesses, &archetypes);
+    }
+
+    #[test]
+    fn overlapping_read_read_is_compatible() {
+        let position = ComponentTypeId::of::<Position>();
+        let accesses = vec![
+            permissions(&[position], &[]),
+            permissions(&[position], &[]),
+        ];
+        let archetypes = vec![archetypes(&[0]), archetypes(&[0])];
+
+        assert_query_access_compatible(&SystemId::from("test"), &accesses, &archetypes);
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicting access")]
+    fn overlapping_read_write_conflicts() {
+        let position = ComponentTypeId::of::<Position>();
+        let accesses = vec![
+            permissions(&[position], &[]),
+            permissions(&[], &[position]),
+        ];
+        let archetypes = vec![archetypes(&[0]), archetypes(&[0])];
+
+        assert_query_access_compatible(&SystemId::from("test"), &accesses, &archetypes);
+    }
+
+    #[test]
+    #[should_panic(expected = "conflicting access")]
+    fn overlapping_write_write_conflicts() {
+        let position = ComponentTypeId::of::<Position>();
+        let accesses = vec![
+            permissions(&[], &[position]),
+            permissions(&[], &[position]),
+        ];
+        let archetypes = vec![archetypes(&[0]), archetypes(&[0])];
+
+        assert_query_access_compatible(&SystemId::from("test"), &accesses, &archetypes);
+    }
+
+    #[test]
+    fn unrelated_components_on_overlapping_archetypes_are_compatible() {
+        let position = ComponentTypeId::of::<Position>();
+        let velocity = ComponentTypeId::of::<Velocity>();
+        let accesses = vec![
+            permissions(&[], &[position]),
+            permissions(&[], &[velocity]),
+        ];
+        let archetypes = vec![archetypes(&[0]), archetypes(&[0])];
+
+        assert_query_access_compatible(&SystemId::from("test"), &accesses, &archetypes);
+    }
+
+    #[test]
+    fn should_run_variants_are_distinct() {
+        assert_ne!(ShouldRun::Yes, ShouldRun::No);
+        assert_ne!(ShouldRun::Yes, ShouldRun::YesAndCheckAgain);
+        assert_ne!(ShouldRun::No, ShouldRun::NoAndCheckAgain);
+        assert_ne!(ShouldRun::YesAndCheckAgain, ShouldRun::NoAndCheckAgain);
+    }
+
+    #[test]
+    fn run_once_criteria_runs_exactly_once() {
+        let world = World::default();
+        let resources = Resources::default();
+        let mut criteria = run_once();
+
+        assert_eq!(criteria(&world, &resources), ShouldRun::Yes);
+        assert_eq!(criteria(&world, &resources), ShouldRun::No);
+        assert_eq!(criteria(&world, &resources), ShouldRun::No);
+    }
+
+    #[test]
+    fn run_criteria_that_never_converges_does_not_hang() {
+        let world = World::default();
+        let resources = Resources::default();
+
+        let mut system = SystemBuilder::new("never_converges")
+            .with_run_criteria(|_: &World, _: &Resources| ShouldRun::NoAndCheckAgain)
+            .build(|_, _, _, _| {
+                panic!("a criteria stuck on NoAndCheckAgain must never let the system run");
+            });
+
+        // Must return instead of busy-spinning forever; see `MAX_RUN_CRITERIA_REEVALUATIONS`.
+        unsafe {
+            system.run_unsafe(&world, &resources);
+        }
+    }
+
+    #[test]
+    fn system_access_is_read_only_tracks_writes() {
+        let read_only = SystemAccess {
+            resources: Permissions::default(),
+            components: permissions(&[ComponentTypeId::of::<Position>()], &[]),
+        };
+        assert!(read_only.is_read_only());
+
+        let writer = SystemAccess {
+            resources: Permissions::default(),
+            components: permissions(&[], &[ComponentTypeId::of::<Position>()]),
+        };
+        assert!(!writer.is_read_only());
+    }
+
+    #[test]
+    fn get_readonly_is_available_only_without_write_access() {
+        let world = World::default();
+
+        let read_only = FunctionSystem {
+            name: SystemId::from("read_only"),
+            func: (),
+            state: (),
+            access: SystemAccess {
+                resources: Permissions::default(),
+                components: permissions(&[ComponentTypeId::of::<Position>()], &[]),
+            },
+            archetypes: ArchetypeAccess::Some(BitSet::default()),
+        };
+        assert!(read_only.get_readonly(&world).is_some());
+
+        let writer = FunctionSystem {
+            name: SystemId::from("writer"),
+            func: (),
+            state: (),
+            access: SystemAccess {
+                resources: Permissions::default(),
+                components: permissions(&[], &[ComponentTypeId::of::<Position>()]),
+            },
+            archetypes: ArchetypeAccess::Some(BitSet::default()),
+        };
+        assert!(writer.get_readonly(&world).is_none());
+    }
+
+    #[derive(Default)]
+    struct Counter(u32);
+
+    type CounterMut<'a> = <Write<Counter> as ResourceSet<'a>>::Result;
+
+    #[test]
+    fn into_system_fetches_read_resource() {
+        let world = World::default();
+        let mut resources = Resources::default();
+        resources.insert(Counter(7));
+
+        fn read_counter(counter: ReadResource<'_, Counter>) {
+            assert_eq!((**counter).0, 7);
+        }
+
+        let mut system = read_counter.into_system("read_counter".into());
+        unsafe {
+            system.run_unsafe(&world, &resources);
+        }
+    }
+
+    #[test]
+    fn into_system_fetches_write_resource() {
+        let world = World::default();
+        let mut resources = Resources::default();
+        resources.insert(Counter(1));
+
+        fn increment(mut counter: CounterMut<'_>) {
+            counter.0 += 1;
+        }
+
+        let mut system = increment.into_system("increment".into());
+        unsafe {
+            system.run_unsafe(&world, &resources);
+        }
+
+        fn assert_incremented(counter: ReadResource<'_, Counter>) {
+            assert_eq!((**counter).0, 2);
+        }
+
+        let mut system = assert_incremented.into_system("assert_incremented".into());
+        unsafe {
+            system.run_unsafe(&world, &resources);
+        }
+    }
+
+    #[test]
+    fn into_system_fetches_query_param() {
+        let world = World::default();
+        let resources = Resources::default();
+
+        fn visits_positions(query: &mut Query<(Read<Position>,)>) {
+            let _ = query;
+        }
+
+        let mut system = visits_positions.into_system("visits_positions".into());
+        unsafe {
+            system.run_unsafe(&world, &resources);
+        }
+    }
+
+    #[test]
+    fn function_system_command_buffer_is_reachable_after_run() {
+        let world = World::default();
+        let resources = Resources::default();
+
+        fn takes_commands(cmd: &mut CommandBuffer) {
+            let _ = cmd;
+        }
+
+        let mut system = takes_commands.into_system("takes_commands".into());
+        unsafe {
+            system.run_unsafe(&world, &resources);
+        }
+
+        assert!(system.command_buffer_mut(world.id()).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "system parameter 1 conflicts")]
+    fn into_system_panics_on_conflicting_mutable_access() {
+        fn conflicting(_a: CounterMut<'_>, _b: CounterMut<'_>) {}
+
+        let _system = conflicting.into_system("conflicting".into());
+    }
+}